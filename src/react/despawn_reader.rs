@@ -4,9 +4,60 @@ use crate::prelude::*;
 //third-party shortcuts
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use bevy::utils::HashMap;
 
 //standard shortcuts
+use std::any::{Any, TypeId};
 
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A type-erased snapshot of components copied off an entity when its despawn reactor was
+/// registered.
+///
+/// Populated by [`DespawnAccessTracker::prepare`] and only valid while the associated reactor is
+/// running (see [`DespawnEvent::component`]).
+pub(crate) type DespawnSnapshot = HashMap<TypeId, Box<dyn Any + Send + Sync>>;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Implemented for tuples of cloneable [`Component`] types that can be requested via
+/// `despawn::<(A, B, ..)>(entity)` and read back with [`DespawnEvent::component`].
+///
+/// [`Self::snapshot`] is what actually "copies the requested components off the entity";
+/// [`DespawnAccessTracker::prepare`] calls it with the world as it is when the trigger is
+/// registered, since that's the only point in the entity's lifetime every despawn path is
+/// guaranteed to run through (see [`DespawnAccessTracker::prepare`]).
+pub trait DespawnSnapshotRequest
+{
+    /// Copies every requested component present on `entity` into a [`DespawnSnapshot`].
+    fn snapshot(world: &World, entity: Entity) -> DespawnSnapshot;
+}
+
+macro_rules! impl_despawn_snapshot_request {
+    ($($component:ident),*) => {
+        impl<$($component: Component + Clone),*> DespawnSnapshotRequest for ($($component,)*)
+        {
+            #[allow(unused, non_snake_case)]
+            fn snapshot(world: &World, entity: Entity) -> DespawnSnapshot
+            {
+                let mut snapshot = DespawnSnapshot::default();
+                $(
+                    if let Some($component) = world.get::<$component>(entity)
+                    {
+                        snapshot.insert(TypeId::of::<$component>(), Box::new($component.clone()));
+                    }
+                )*
+                snapshot
+            }
+        }
+    };
+}
+
+impl_despawn_snapshot_request!();
+impl_despawn_snapshot_request!(A);
+impl_despawn_snapshot_request!(A, B);
+impl_despawn_snapshot_request!(A, B, C);
+impl_despawn_snapshot_request!(A, B, C, D);
 
 //-------------------------------------------------------------------------------------------------------------------
 
@@ -16,45 +67,80 @@ pub(crate) struct DespawnAccessTracker
 {
     /// True when in a system reacting to an entity reaction.
     currently_reacting: bool,
-    /// The source of the most recent entity reaction.
-    reaction_source: Entity,
+    /// The sources of the current entity reaction, in the order they were despawned.
+    reaction_sources: Vec<Entity>,
     /// A handle to the current reactor.
     ///
     /// This will be dropped after the reactor runs, allowing it to be cleaned up automatically.
     reactor_handle: Option<ReactorHandle>,
+    /// The component snapshots of the current entity reaction, one per entry in
+    /// `reaction_sources` at the same index.
+    ///
+    /// Empty unless the reactor's trigger requested specific component types to snapshot.
+    snapshots: Vec<DespawnSnapshot>,
 
     /// Reaction information cached for when the reaction system actually runs.
-    prepared: Vec<(SystemCommand, Entity, ReactorHandle)>,
+    ///
+    /// Sources despawned for the same reactor are coalesced into one entry so a single reactor
+    /// run can drain all of them via [`DespawnEvent::read`].
+    prepared: Vec<(SystemCommand, Vec<Entity>, ReactorHandle, Vec<DespawnSnapshot>)>,
 }
 
 impl DespawnAccessTracker
 {
-    /// Caches metadata for an entity reaction.
-    pub(crate) fn prepare(&mut self, reactor: SystemCommand, source: Entity, handle: ReactorHandle)
+    /// Caches metadata for an entity reaction and marks `source` with [`DespawnReactive`] so its
+    /// removal is detected by [`register_despawn_bridge`] no matter how it is later despawned.
+    ///
+    /// Must run when the `despawn(entity)` trigger is registered (i.e. at
+    /// `c.react().on(despawn(entity), ..)` time), not when the entity is actually despawned: the
+    /// entity can outlive its reactor registration by an arbitrary amount of time, and may end up
+    /// despawned through cobweb's own despawn command, directly via [`EntityWorldMut::despawn`], or
+    /// by another plugin entirely. Registration is the only point common to all of those paths, so
+    /// both the marker and `R`'s component snapshot are taken here rather than waiting for whatever
+    /// despawn happens to come along.
+    ///
+    /// If `reactor` already has a prepared reaction pending, `source` is coalesced into it so both
+    /// entities are delivered in the same reactor run (see [`DespawnEvent::read`]).
+    pub(crate) fn prepare<R: DespawnSnapshotRequest>(
+        &mut self,
+        world: &mut World,
+        reactor: SystemCommand,
+        source: Entity,
+        handle: ReactorHandle,
+    )
     {
-        self.prepared.push((reactor, source, handle));
+        let snapshot = R::snapshot(world, source);
+        world.entity_mut(source).insert(DespawnReactive);
+
+        if let Some((_, sources, _, snapshots)) = self.prepared.iter_mut().find(|(r, ..)| *r == reactor)
+        {
+            sources.push(source);
+            snapshots.push(snapshot);
+            return;
+        }
+
+        self.prepared.push((reactor, vec![source], handle, vec![snapshot]));
     }
 
-    /// Sets metadata for the current entity reaction.
-    pub(crate) fn start(&mut self, reactor: SystemCommand)
+    /// Sets metadata for the current entity reaction from a `prepared` entry taken by
+    /// [`Self::take_prepared_for`].
+    pub(crate) fn start(&mut self, entry: (SystemCommand, Vec<Entity>, ReactorHandle, Vec<DespawnSnapshot>))
     {
-        let Some(pos) = self.prepared.iter().position(|(s, _, _)| *s == reactor) else {
-            tracing::error!("prepared despawn entity reaction is missing {:?}", reactor);
-            debug_assert!(false);
-            return;
-        };
-        let (_, source, handle) = self.prepared.swap_remove(pos);
+        let (_, sources, handle, snapshots) = entry;
 
         self.currently_reacting = true;
-        self.reaction_source = source;
+        self.reaction_sources = sources;
         self.reactor_handle = Some(handle);
+        self.snapshots = snapshots;
     }
 
-    /// Unsets the 'is reacting' flag and drops the auto despawn signal.
+    /// Unsets the 'is reacting' flag and drops the auto despawn signal and component snapshots.
     pub(crate) fn end(&mut self)
     {
         self.currently_reacting = false;
         self.reactor_handle = None;
+        self.reaction_sources.clear();
+        self.snapshots.clear();
     }
 
     /// Returns `true` if an entity reaction is currently being processed.
@@ -63,10 +149,75 @@ impl DespawnAccessTracker
         self.currently_reacting
     }
 
-    /// Returns the source of the most recent entity reaction.
-    fn source(&self) -> Entity
+    /// Returns the first source of the current entity reaction.
+    fn source(&self) -> Option<Entity>
+    {
+        self.reaction_sources.first().copied()
+    }
+
+    /// Returns all sources of the current entity reaction.
+    fn sources(&self) -> &[Entity]
+    {
+        &self.reaction_sources
+    }
+
+    /// Returns the snapshotted component of type `T` for the first source of the current entity
+    /// reaction, if it was requested at registration time.
+    ///
+    /// See [`Self::component_for`] to read a snapshot for a specific coalesced source.
+    fn component<T: 'static>(&self) -> Option<&T>
     {
-        self.reaction_source
+        self.snapshots.first()?.get(&TypeId::of::<T>())?.downcast_ref::<T>()
+    }
+
+    /// Returns the snapshotted component of type `T` for `source`, if it was requested at
+    /// registration time and `source` is part of the current entity reaction.
+    fn component_for<T: 'static>(&self, source: Entity) -> Option<&T>
+    {
+        let index = self.reaction_sources.iter().position(|s| *s == source)?;
+        self.snapshots.get(index)?.get(&TypeId::of::<T>())?.downcast_ref::<T>()
+    }
+
+    /// Removes and returns the full `prepared` entries for `entity`, if any.
+    ///
+    /// Used by [`register_despawn_bridge`] so a removal forwards its reaction exactly once,
+    /// instead of leaving a stale `prepared` entry that a later, unrelated removal would
+    /// re-trigger. Returns the whole entry (not just the reactor) because the caller has already
+    /// removed it from `prepared` and must hand it to [`Self::start`] directly — a second lookup
+    /// by reactor would never find it.
+    fn take_prepared_for(&mut self, entity: Entity) -> Vec<(SystemCommand, Vec<Entity>, ReactorHandle, Vec<DespawnSnapshot>)>
+    {
+        let (matched, kept): (Vec<_>, Vec<_>) =
+            std::mem::take(&mut self.prepared).into_iter().partition(|(_, sources, ..)| sources.contains(&entity));
+        self.prepared = kept;
+        matched
+    }
+
+    /// Runs the despawn reaction for a `prepared` entry (see [`Self::take_prepared_for`]) to
+    /// completion via the normal `start` -> run -> `end` lifecycle, flushing the world immediately
+    /// before and after.
+    ///
+    /// Flushing first guarantees `Entities` is in a consistent state before the reactor runs, and
+    /// flushing after applies anything it spawned. Without this, a `Commands::spawn` issued from
+    /// inside a despawn reactor would panic reserving into an unflushed allocator, matching Bevy
+    /// issue #14467.
+    ///
+    /// [`register_despawn_bridge`] is the only caller, and thus the only place a despawn reaction
+    /// actually runs: cobweb's own despawn command doesn't have a separate execution path of its
+    /// own, it just despawns the entity like anything else and relies on this same removal hook to
+    /// pick up the reaction. That means every despawn reaction gets this flush guarantee
+    /// regardless of how the entity was despawned.
+    pub(crate) fn react(world: &mut World, entry: (SystemCommand, Vec<Entity>, ReactorHandle, Vec<DespawnSnapshot>))
+    {
+        let reactor = entry.0;
+
+        world.flush();
+
+        world.resource_scope(|_world, mut tracker: Mut<DespawnAccessTracker>| tracker.start(entry));
+        reactor.run(world);
+        world.resource_scope(|_world, mut tracker: Mut<DespawnAccessTracker>| tracker.end());
+
+        world.flush();
     }
 }
 
@@ -76,8 +227,9 @@ impl Default for DespawnAccessTracker
     {
         Self{
             currently_reacting: false,
-            reaction_source: Entity::from_raw_u32(0u32).unwrap(),
+            reaction_sources: Vec::default(),
             reactor_handle: None,
+            snapshots: Vec::default(),
             prepared: Vec::default(),
         }
     }
@@ -85,11 +237,66 @@ impl Default for DespawnAccessTracker
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Marker component inserted on an entity by [`DespawnAccessTracker::prepare`] at registration
+/// time, so its removal can be detected no matter how it is later despawned.
+#[derive(Component)]
+pub(crate) struct DespawnReactive;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Installs the `on_remove` component hook on [`DespawnReactive`] that bridges Bevy's entity
+/// removal lifecycle into cobweb's despawn reactions.
+///
+/// This is the only place a despawn reaction actually runs, regardless of whether the entity went
+/// through cobweb's own despawn command or was removed directly via [`EntityWorldMut::despawn`] or
+/// by another plugin — cobweb's despawn command has no separate execution path, it just despawns
+/// the entity like anything else and relies on the [`DespawnReactive`] marker that
+/// [`DespawnAccessTracker::prepare`] already inserted at registration time. This hook fires on
+/// every removal of [`DespawnReactive`] and forwards it to [`DespawnAccessTracker`], running each
+/// matched reactor through the normal `start` -> run -> `end` lifecycle.
+///
+/// The hook runs inside the despawn flush, where `Entities` has not been flushed yet, so the
+/// forwarded reaction is staged as a command rather than run synchronously; running it here would
+/// panic (see Bevy's fix for despawn-time entity reservation).
+pub(crate) fn register_despawn_bridge(world: &mut World)
+{
+    world.register_component_hooks::<DespawnReactive>().on_remove(|mut world, entity, _component_id| {
+        world.commands().queue(move |world: &mut World| {
+            let prepared = world.resource_mut::<DespawnAccessTracker>().take_prepared_for(entity);
+            for entry in prepared
+            {
+                DespawnAccessTracker::react(world, entry);
+            }
+        });
+    });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Plugin that wires cobweb's despawn reactions into Bevy's entity removal lifecycle.
+///
+/// Installs [`register_despawn_bridge`] at startup so `DespawnEvent` fires for entities despawned
+/// through any code path, not only cobweb's own despawn command (see [`register_despawn_bridge`]).
+pub(crate) struct DespawnReactionPlugin;
+
+impl Plugin for DespawnReactionPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<DespawnAccessTracker>();
+        register_despawn_bridge(app.world_mut());
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// System parameter for reading entity despawn events in systems that react to those events.
 ///
 /// Can only be used within [`SystemCommands`](super::SystemCommand).
 ///
-/// Use [`despawn`] to make a trigger that will read these events.
+/// Use [`despawn`] to make a trigger that will read these events. Use `despawn::<(A, B, ...)>` to
+/// also snapshot specific components off the entity when the trigger is registered, which can then
+/// be read back with [`Self::component`].
 ///
 /*
 ```rust
@@ -97,11 +304,12 @@ fn example(mut c: Commands)
 {
     let entity = c.spawn_empty().id();
     c.react().on(
-        despawn(entity),
+        despawn::<(Name,)>(entity),
         |event: DespawnEvent|
         {
             let entity = event.get()?;
-            println!("{:?} was despawned", entity);
+            let name = event.component::<Name>()?;
+            println!("{:?} ({:?}) was despawned", entity, name);
             DONE
         }
     );
@@ -118,9 +326,10 @@ pub struct DespawnEvent<'w>
 
 impl<'w> DespawnEvent<'w>
 {
-    /// Returns the entity that was despawned that the current system is reacting to.
+    /// Returns the first entity that was despawned that the current system is reacting to.
     ///
-    /// This will return at most one unique entity each time a reactor runs.
+    /// Use [`Self::read`] to drain all despawned entities when multiple were coalesced into this
+    /// reactor run.
     ///
     /// Panics if the system is not reacting to a despawn.
     pub fn entity(&self) -> Entity
@@ -132,16 +341,209 @@ impl<'w> DespawnEvent<'w>
     pub fn get(&self) -> Result<Entity, CobwebReactError>
     {
         if !self.tracker.is_reacting() { return Err(CobwebReactError::DespawnEvent); }
-        Ok(self.tracker.source())
+        self.tracker.source().ok_or(CobwebReactError::DespawnEvent)
+    }
+
+    /// Iterates all entities despawned for this reactor run.
+    ///
+    /// Multiple despawns can be coalesced into one run when they share the same reactor (see
+    /// [`DespawnAccessTracker::prepare`]), mirroring Bevy's `EventReader::read`. Returns an empty
+    /// iterator if the system is not reacting to a despawn.
+    pub fn read(&self) -> impl Iterator<Item = Entity> + '_
+    {
+        self.tracker.sources().iter().copied()
     }
 
     /// Returns `true` if there is nothing to read.
     ///
-    /// Equivalent to `event.read().is_none()`.
+    /// Equivalent to `event.read().next().is_none()`.
     pub fn is_empty(&self) -> bool
     {
         self.get().is_err()
     }
+
+    /// Returns a snapshot of component `T` taken from [`Self::entity`] when its despawn reactor was
+    /// registered.
+    ///
+    /// Only available if `T` was requested in the trigger that registered this reactor, e.g. via
+    /// `despawn::<(T, ..)>(entity)`. Use [`Self::component_of`] to read a snapshot for a specific
+    /// entity when multiple were coalesced into this run (see [`Self::read`]).
+    ///
+    /// Errors if the system is not reacting to a despawn, or if `T` was not snapshotted.
+    pub fn component<T: 'static>(&self) -> Result<&T, CobwebReactError>
+    {
+        if !self.tracker.is_reacting() { return Err(CobwebReactError::DespawnEvent); }
+        self.tracker.component::<T>().ok_or(CobwebReactError::DespawnEvent)
+    }
+
+    /// Returns a snapshot of component `T` taken from `source` when its despawn reactor was
+    /// registered.
+    ///
+    /// See [`Self::component`].
+    pub fn component_of<T: 'static>(&self, source: Entity) -> Result<&T, CobwebReactError>
+    {
+        if !self.tracker.is_reacting() { return Err(CobwebReactError::DespawnEvent); }
+        self.tracker.component_for::<T>(source).ok_or(CobwebReactError::DespawnEvent)
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests
+{
+    use std::sync::{Arc, Mutex};
+
+    use bevy::app::App;
+
+    use super::*;
+
+    fn test_app() -> App
+    {
+        let mut app = App::new();
+        app.add_plugins((ReactPlugin, DespawnReactionPlugin));
+        app
+    }
+
+    /// Reproduces the scenario in the request: two despawns that coalesce onto the same reactor
+    /// should both show up in one `DespawnEvent::read()` call, not one call per entity.
+    #[test]
+    fn coalesced_despawns_are_all_readable_in_one_run()
+    {
+        let mut app = test_app();
+        let entity_a = app.world_mut().spawn_empty().id();
+        let entity_b = app.world_mut().spawn_empty().id();
+
+        let seen = Arc::new(Mutex::new(Vec::<Vec<Entity>>::new()));
+        let seen_inner = seen.clone();
+
+        app.world_mut().commands().react().on(
+            (despawn(entity_a), despawn(entity_b)),
+            move |event: DespawnEvent|
+            {
+                seen_inner.lock().unwrap().push(event.read().collect());
+                DONE
+            },
+        );
+        app.world_mut().flush();
+
+        app.world_mut().commands().despawn(entity_a);
+        app.world_mut().commands().despawn(entity_b);
+        app.update();
+
+        assert_eq!(*seen.lock().unwrap(), vec![vec![entity_a, entity_b]]);
+    }
+
+    /// Regression test for Bevy issue #14467: spawning an entity from inside a despawn reactor
+    /// must not panic reserving into an unflushed `Entities`.
+    #[test]
+    fn spawning_inside_a_despawn_reactor_does_not_panic()
+    {
+        let mut app = test_app();
+        let entity = app.world_mut().spawn_empty().id();
+
+        app.world_mut().commands().react().on(
+            despawn(entity),
+            |mut c: Commands|
+            {
+                c.spawn_empty();
+                DONE
+            },
+        );
+        app.world_mut().flush();
+
+        app.world_mut().commands().despawn(entity);
+        app.update();
+    }
+
+    /// Reproduces the chunk0-3 scenario: an entity removed directly via `World::despawn`, never
+    /// going through cobweb's own despawn command, still fires its despawn reactor through
+    /// [`register_despawn_bridge`]. Registering the trigger is what marks the entity with
+    /// [`DespawnReactive`] (see [`DespawnAccessTracker::prepare`]), so nothing extra is needed here
+    /// beyond despawning the entity some other way.
+    #[test]
+    fn despawning_outside_cobwebs_command_still_reacts()
+    {
+        let mut app = test_app();
+        let entity = app.world_mut().spawn_empty().id();
+
+        let seen = Arc::new(Mutex::new(None));
+        let seen_inner = seen.clone();
+
+        app.world_mut().commands().react().on(
+            despawn(entity),
+            move |event: DespawnEvent|
+            {
+                *seen_inner.lock().unwrap() = event.get().ok();
+                DONE
+            },
+        );
+        app.world_mut().flush();
+
+        app.world_mut().despawn(entity);
+        app.update();
+
+        assert_eq!(*seen.lock().unwrap(), Some(entity));
+    }
+
+    /// Coalescing (see [`coalesced_despawns_are_all_readable_in_one_run`]) also has to hold for
+    /// entities despawned outside cobweb's own command, since they run through the same
+    /// [`register_despawn_bridge`] path.
+    #[test]
+    fn coalesced_despawns_via_the_bridge_are_all_readable_in_one_run()
+    {
+        let mut app = test_app();
+        let entity_a = app.world_mut().spawn_empty().id();
+        let entity_b = app.world_mut().spawn_empty().id();
+
+        let seen = Arc::new(Mutex::new(Vec::<Vec<Entity>>::new()));
+        let seen_inner = seen.clone();
+
+        app.world_mut().commands().react().on(
+            (despawn(entity_a), despawn(entity_b)),
+            move |event: DespawnEvent|
+            {
+                seen_inner.lock().unwrap().push(event.read().collect());
+                DONE
+            },
+        );
+        app.world_mut().flush();
+
+        app.world_mut().despawn(entity_a);
+        app.world_mut().despawn(entity_b);
+        app.update();
+
+        assert_eq!(*seen.lock().unwrap(), vec![vec![entity_a, entity_b]]);
+    }
+
+    /// Regression test for Bevy issue #14467, for an entity despawned outside cobweb's own
+    /// despawn command (see [`despawning_outside_cobwebs_command_still_reacts`]).
+    ///
+    /// The flush guarantee lives in [`DespawnAccessTracker::react`], which is the only place any
+    /// despawn reaction runs (see its doc comment), so this covers cobweb-driven despawns too —
+    /// there's no separate path left for them to fall through.
+    #[test]
+    fn spawning_inside_a_bridged_despawn_reactor_does_not_panic()
+    {
+        let mut app = test_app();
+        let entity = app.world_mut().spawn_empty().id();
+        let spawned = Arc::new(Mutex::new(None));
+        let spawned_inner = spawned.clone();
+
+        app.world_mut().commands().react().on(
+            despawn(entity),
+            move |mut c: Commands|
+            {
+                *spawned_inner.lock().unwrap() = Some(c.spawn_empty().id());
+                DONE
+            },
+        );
+        app.world_mut().flush();
+
+        app.world_mut().despawn(entity);
+        app.update();
+
+        let spawned_entity = spawned.lock().unwrap().expect("reactor should have spawned an entity");
+        assert!(app.world().entities().contains(spawned_entity));
+    }
+}